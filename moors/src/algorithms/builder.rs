@@ -0,0 +1,378 @@
+use std::fmt;
+use std::path::PathBuf;
+
+use crate::{
+    algorithms::{
+        ga::GeneticAlgorithm,
+        helpers::AlgorithmContext,
+        rates::{MutationRate, SelectionRate},
+        stopping::StoppingCriterion,
+        surrogate::Surrogate,
+    },
+    duplicates::PopulationCleaner,
+    evaluator::{ConstraintsFn, Evaluator, FitnessFn},
+    genetic::Population,
+    operators::{
+        CrossoverOperator, Evolve, MutationOperator, SamplingOperator, SelectionOperator,
+        SurvivalOperator,
+    },
+    random::MOORandomGenerator,
+};
+
+/// Fluent builder for [`GeneticAlgorithm`], used directly for custom
+/// operator combinations and wrapped by the per-algorithm builders
+/// (`Nsga2Builder`, `Spea2Builder`, …) that preset the survival/selection
+/// operators for a given family.
+pub struct AlgorithmBuilder<S, Sel, Sur, Cross, Mut, F, G, DC>
+where
+    S: SamplingOperator,
+    Sel: SelectionOperator<FDim = F::Dim>,
+    Sur: SurvivalOperator<FDim = F::Dim>,
+    Cross: CrossoverOperator,
+    Mut: MutationOperator,
+    F: FitnessFn,
+    G: ConstraintsFn,
+    DC: PopulationCleaner,
+{
+    sampler: Option<S>,
+    selector: Option<Sel>,
+    survivor: Option<Sur>,
+    crossover: Option<Cross>,
+    mutation: Option<Mut>,
+    duplicates_cleaner: Option<DC>,
+    fitness_fn: Option<F>,
+    constraints_fn: Option<G>,
+    num_vars: Option<usize>,
+    population_size: Option<usize>,
+    num_offsprings: Option<usize>,
+    num_iterations: Option<usize>,
+    crossover_rate: f64,
+    mutation_rate: f64,
+    verbose: bool,
+    seed: Option<u64>,
+    stopping_criteria: Vec<Box<dyn StoppingCriterion<F::Dim, G::Dim>>>,
+    resume_path: Option<PathBuf>,
+    surrogate: Option<(Box<dyn Surrogate<F::Dim>>, usize)>,
+    fitness_cache: Option<(f64, Option<usize>)>,
+    adaptive_mutation_rate: Option<Box<dyn MutationRate>>,
+    adaptive_selection_rate: Option<Box<dyn SelectionRate>>,
+    rate_tracker: Option<Box<dyn FnMut(&Population<F::Dim, G::Dim>) -> f64>>,
+}
+
+impl<S, Sel, Sur, Cross, Mut, F, G, DC> Default for AlgorithmBuilder<S, Sel, Sur, Cross, Mut, F, G, DC>
+where
+    S: SamplingOperator,
+    Sel: SelectionOperator<FDim = F::Dim>,
+    Sur: SurvivalOperator<FDim = F::Dim>,
+    Cross: CrossoverOperator,
+    Mut: MutationOperator,
+    F: FitnessFn,
+    G: ConstraintsFn,
+    DC: PopulationCleaner,
+{
+    fn default() -> Self {
+        Self {
+            sampler: None,
+            selector: None,
+            survivor: None,
+            crossover: None,
+            mutation: None,
+            duplicates_cleaner: None,
+            fitness_fn: None,
+            constraints_fn: None,
+            num_vars: None,
+            population_size: None,
+            num_offsprings: None,
+            num_iterations: None,
+            crossover_rate: 0.9,
+            mutation_rate: 0.1,
+            verbose: false,
+            seed: None,
+            stopping_criteria: Vec::new(),
+            resume_path: None,
+            surrogate: None,
+            fitness_cache: None,
+            adaptive_mutation_rate: None,
+            adaptive_selection_rate: None,
+            rate_tracker: None,
+        }
+    }
+}
+
+/// Either a required builder field was never set before
+/// [`AlgorithmBuilder::build`] was called, or (when [`AlgorithmBuilder::resume_from`]
+/// was used) the checkpoint at the given path could not be loaded.
+#[derive(Debug)]
+pub enum AlgorithmBuilderError {
+    MissingField(&'static str),
+    Resume(std::io::Error),
+}
+
+impl fmt::Display for AlgorithmBuilderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingField(field) => write!(f, "missing required builder field `{}`", field),
+            Self::Resume(err) => write!(f, "failed to resume from checkpoint: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for AlgorithmBuilderError {}
+
+impl<S, Sel, Sur, Cross, Mut, F, G, DC> AlgorithmBuilder<S, Sel, Sur, Cross, Mut, F, G, DC>
+where
+    S: SamplingOperator,
+    Sel: SelectionOperator<FDim = F::Dim>,
+    Sur: SurvivalOperator<FDim = F::Dim>,
+    Cross: CrossoverOperator,
+    Mut: MutationOperator,
+    F: FitnessFn,
+    F::Dim: ndarray::RemoveAxis,
+    G: ConstraintsFn,
+    G::Dim: ndarray::RemoveAxis,
+    DC: PopulationCleaner,
+{
+    pub fn sampler(mut self, sampler: S) -> Self {
+        self.sampler = Some(sampler);
+        self
+    }
+
+    pub fn selector(mut self, selector: Sel) -> Self {
+        self.selector = Some(selector);
+        self
+    }
+
+    pub fn survivor(mut self, survivor: Sur) -> Self {
+        self.survivor = Some(survivor);
+        self
+    }
+
+    pub fn crossover(mut self, crossover: Cross) -> Self {
+        self.crossover = Some(crossover);
+        self
+    }
+
+    pub fn mutation(mut self, mutation: Mut) -> Self {
+        self.mutation = Some(mutation);
+        self
+    }
+
+    pub fn duplicates_cleaner(mut self, duplicates_cleaner: DC) -> Self {
+        self.duplicates_cleaner = Some(duplicates_cleaner);
+        self
+    }
+
+    pub fn fitness_fn(mut self, fitness_fn: F) -> Self {
+        self.fitness_fn = Some(fitness_fn);
+        self
+    }
+
+    pub fn constraints_fn(mut self, constraints_fn: G) -> Self {
+        self.constraints_fn = Some(constraints_fn);
+        self
+    }
+
+    pub fn num_vars(mut self, num_vars: usize) -> Self {
+        self.num_vars = Some(num_vars);
+        self
+    }
+
+    pub fn population_size(mut self, population_size: usize) -> Self {
+        self.population_size = Some(population_size);
+        self
+    }
+
+    pub fn num_offsprings(mut self, num_offsprings: usize) -> Self {
+        self.num_offsprings = Some(num_offsprings);
+        self
+    }
+
+    pub fn num_iterations(mut self, num_iterations: usize) -> Self {
+        self.num_iterations = Some(num_iterations);
+        self
+    }
+
+    pub fn crossover_rate(mut self, crossover_rate: f64) -> Self {
+        self.crossover_rate = crossover_rate;
+        self
+    }
+
+    pub fn mutation_rate(mut self, mutation_rate: f64) -> Self {
+        self.mutation_rate = mutation_rate;
+        self
+    }
+
+    pub fn verbose(mut self, verbose: bool) -> Self {
+        self.verbose = verbose;
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Seeds the built algorithm from a previously saved
+    /// [`Checkpoint`](crate::algorithms::Checkpoint) at `path` instead of
+    /// sampling a fresh initial population: the loaded population, iteration
+    /// counter and RNG state are used in place of sampling and `seed`, and
+    /// the run picks up where the checkpoint left off. The other required
+    /// builder fields (including `sampler`, which is then never called,
+    /// operators, evaluator, `num_iterations`, …) must still be set, since
+    /// `resume_from` only substitutes the starting state, not the run
+    /// configuration.
+    pub fn resume_from(mut self, path: impl Into<PathBuf>) -> Self {
+        self.resume_path = Some(path.into());
+        self
+    }
+
+    /// Installs a [`Surrogate`] that pre-screens offspring each generation:
+    /// it is refit on the current (truly-evaluated) population, used to
+    /// predict every offspring's fitness, and only the `num_screened` most
+    /// promising candidates are passed on to the real evaluator. Equivalent
+    /// to calling [`GeneticAlgorithm::set_surrogate`] on the algorithm
+    /// returned by [`Self::build`].
+    pub fn surrogate(mut self, surrogate: Box<dyn Surrogate<F::Dim>>, num_screened: usize) -> Self {
+        self.surrogate = Some((surrogate, num_screened));
+        self
+    }
+
+    /// Gates the per-genotype fitness/constraints cache behind a builder
+    /// flag: rows of the combined parent+offspring matrix whose quantized
+    /// genome (within `tolerance`) was already evaluated are served from
+    /// the cache instead of being re-evaluated. `capacity` bounds the
+    /// number of entries kept, evicted first-in-first-out once exceeded.
+    /// Equivalent to calling
+    /// [`GeneticAlgorithm::enable_fitness_cache`] on the algorithm returned
+    /// by [`Self::build`].
+    pub fn enable_fitness_cache(mut self, tolerance: f64, capacity: Option<usize>) -> Self {
+        self.fitness_cache = Some((tolerance, capacity));
+        self
+    }
+
+    /// Installs a [`MutationRate`] recomputed every generation in place of
+    /// the fixed `mutation_rate(f64)` set above, requiring a
+    /// [`Self::rate_tracker`] when the rate depends on fitness progress
+    /// rather than only on the generation index. Equivalent to calling
+    /// [`GeneticAlgorithm::set_mutation_rate`] on the algorithm returned by
+    /// [`Self::build`].
+    pub fn adaptive_mutation_rate(mut self, rate: Box<dyn MutationRate>) -> Self {
+        self.adaptive_mutation_rate = Some(rate);
+        self
+    }
+
+    /// Installs a [`SelectionRate`], the parallel counterpart of
+    /// [`Self::adaptive_mutation_rate`] for selection pressure. Equivalent
+    /// to calling [`GeneticAlgorithm::set_selection_rate`] on the algorithm
+    /// returned by [`Self::build`].
+    pub fn adaptive_selection_rate(mut self, rate: Box<dyn SelectionRate>) -> Self {
+        self.adaptive_selection_rate = Some(rate);
+        self
+    }
+
+    /// Supplies the function used to extract the scalar best-fitness (or
+    /// chosen indicator, for MOO) value tracked by slope-adaptive rates.
+    /// Equivalent to calling [`GeneticAlgorithm::set_rate_tracker`] on the
+    /// algorithm returned by [`Self::build`].
+    pub fn rate_tracker(
+        mut self,
+        tracker: Box<dyn FnMut(&Population<F::Dim, G::Dim>) -> f64>,
+    ) -> Self {
+        self.rate_tracker = Some(tracker);
+        self
+    }
+
+    /// Registers an early-stopping rule accepted by the builder alongside
+    /// `num_iterations`: all registered criteria are consulted at the end
+    /// of every generation and the run stops as soon as any one fires.
+    /// Equivalent to calling
+    /// [`GeneticAlgorithm::add_stopping_criterion`] on the algorithm
+    /// returned by [`Self::build`], but lets the criterion be configured in
+    /// the same fluent chain as the rest of the run.
+    pub fn stopping_criterion(
+        mut self,
+        criterion: Box<dyn StoppingCriterion<F::Dim, G::Dim>>,
+    ) -> Self {
+        self.stopping_criteria.push(criterion);
+        self
+    }
+
+    pub fn build(
+        self,
+    ) -> Result<GeneticAlgorithm<S, Sel, Sur, Cross, Mut, F, G, DC>, AlgorithmBuilderError> {
+        let sampler = self.sampler.ok_or(AlgorithmBuilderError::MissingField("sampler"))?;
+        let selector = self.selector.ok_or(AlgorithmBuilderError::MissingField("selector"))?;
+        let survivor = self.survivor.ok_or(AlgorithmBuilderError::MissingField("survivor"))?;
+        let crossover = self.crossover.ok_or(AlgorithmBuilderError::MissingField("crossover"))?;
+        let mutation = self.mutation.ok_or(AlgorithmBuilderError::MissingField("mutation"))?;
+        let duplicates_cleaner = self
+            .duplicates_cleaner
+            .ok_or(AlgorithmBuilderError::MissingField("duplicates_cleaner"))?;
+        let fitness_fn = self.fitness_fn.ok_or(AlgorithmBuilderError::MissingField("fitness_fn"))?;
+        let constraints_fn = self
+            .constraints_fn
+            .ok_or(AlgorithmBuilderError::MissingField("constraints_fn"))?;
+        let num_vars = self.num_vars.ok_or(AlgorithmBuilderError::MissingField("num_vars"))?;
+        let population_size = self
+            .population_size
+            .ok_or(AlgorithmBuilderError::MissingField("population_size"))?;
+        let num_offsprings = self
+            .num_offsprings
+            .ok_or(AlgorithmBuilderError::MissingField("num_offsprings"))?;
+        let num_iterations = self
+            .num_iterations
+            .ok_or(AlgorithmBuilderError::MissingField("num_iterations"))?;
+
+        let evolve = Evolve::new(
+            selector,
+            crossover,
+            mutation,
+            duplicates_cleaner,
+            self.crossover_rate,
+            self.mutation_rate,
+        );
+        let evaluator = Evaluator::new(fitness_fn, constraints_fn);
+        let context = AlgorithmContext::new(num_vars, population_size, num_offsprings, num_iterations);
+
+        let mut algorithm = match self.resume_path {
+            Some(path) => GeneticAlgorithm::resume_from(
+                path,
+                sampler,
+                survivor,
+                evolve,
+                evaluator,
+                context,
+                self.verbose,
+            )
+            .map_err(AlgorithmBuilderError::Resume)?,
+            None => {
+                let rng = match self.seed {
+                    Some(seed) => MOORandomGenerator::new_with_seed(seed),
+                    None => MOORandomGenerator::new(),
+                };
+                GeneticAlgorithm::new(None, sampler, survivor, evolve, evaluator, context, self.verbose, rng)
+            }
+        };
+
+        for criterion in self.stopping_criteria {
+            algorithm.add_stopping_criterion(criterion);
+        }
+        if let Some((surrogate, num_screened)) = self.surrogate {
+            algorithm.set_surrogate(surrogate, num_screened);
+        }
+        if let Some((tolerance, capacity)) = self.fitness_cache {
+            algorithm.enable_fitness_cache(tolerance, capacity);
+        }
+        if let Some(rate) = self.adaptive_mutation_rate {
+            algorithm.set_mutation_rate(rate);
+        }
+        if let Some(rate) = self.adaptive_selection_rate {
+            algorithm.set_selection_rate(rate);
+        }
+        if let Some(tracker) = self.rate_tracker {
+            algorithm.set_rate_tracker(tracker);
+        }
+
+        Ok(algorithm)
+    }
+}
@@ -0,0 +1,182 @@
+use std::collections::{HashMap, VecDeque};
+
+use ndarray::{Array, Array2, ArrayView1, Axis, RemoveAxis, concatenate};
+
+use crate::{
+    evaluator::{ConstraintsFn, Evaluator, EvaluatorError, FitnessFn},
+    genetic::{D12, Population},
+};
+
+/// A quantized genome, used as the cache key so that near-identical float
+/// genomes collide, matching `CloseDuplicatesCleaner`'s tolerance.
+type Key = Vec<i64>;
+
+fn quantize(row: ArrayView1<f64>, tolerance: f64) -> Key {
+    if tolerance <= 0.0 {
+        row.iter().map(|v| v.to_bits() as i64).collect()
+    } else {
+        row.iter().map(|v| (v / tolerance).round() as i64).collect()
+    }
+}
+
+/// Memoizes `Evaluator::evaluate` per genome row, so individuals that
+/// survive unchanged across generations (and offspring duplicating an
+/// already-seen genome) are not re-evaluated.
+///
+/// Disabled by default; enable it via
+/// [`GeneticAlgorithm::enable_fitness_cache`](crate::algorithms::GeneticAlgorithm::enable_fitness_cache).
+#[derive(Debug)]
+pub struct EvaluationCache<FDim, GDim>
+where
+    FDim: D12 + RemoveAxis,
+    GDim: D12 + RemoveAxis,
+{
+    tolerance: f64,
+    capacity: Option<usize>,
+    order: VecDeque<Key>,
+    entries: HashMap<Key, (Array<f64, FDim>, Array<f64, GDim>)>,
+}
+
+impl<FDim, GDim> EvaluationCache<FDim, GDim>
+where
+    FDim: D12 + RemoveAxis,
+    GDim: D12 + RemoveAxis,
+{
+    /// `tolerance` controls how close two genomes must be to collide into
+    /// the same cache entry; `capacity` bounds the number of entries kept,
+    /// evicted first-in-first-out once exceeded.
+    pub fn new(tolerance: f64, capacity: Option<usize>) -> Self {
+        Self {
+            tolerance,
+            capacity,
+            order: VecDeque::new(),
+            entries: HashMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn insert(&mut self, key: Key, fitness: Array<f64, FDim>, constraints: Array<f64, GDim>) {
+        if !self.entries.contains_key(&key) {
+            if let Some(capacity) = self.capacity {
+                if self.entries.len() >= capacity {
+                    if let Some(oldest) = self.order.pop_front() {
+                        self.entries.remove(&oldest);
+                    }
+                }
+            }
+            self.order.push_back(key.clone());
+        }
+        self.entries.insert(key, (fitness, constraints));
+    }
+
+    /// Evaluates `genes` through `evaluator`, skipping rows whose quantized
+    /// genome is already cached and stitching cached and freshly computed
+    /// rows back into a `Population` in the original row order.
+    pub fn evaluate<F, G>(
+        &mut self,
+        evaluator: &Evaluator<F, G>,
+        genes: Array2<f64>,
+    ) -> Result<Population<FDim, GDim>, EvaluatorError>
+    where
+        F: FitnessFn<Dim = FDim>,
+        G: ConstraintsFn<Dim = GDim>,
+    {
+        let keys: Vec<Key> = (0..genes.nrows())
+            .map(|i| quantize(genes.row(i), self.tolerance))
+            .collect();
+
+        // Snapshot every row this batch already has cached *before* any
+        // eviction below. Capacity-bound eviction is FIFO and has no notion
+        // of "still needed by the in-flight batch", so inserting one miss
+        // could otherwise evict a hit (or another miss recorded earlier in
+        // this same batch) before we get to read it back, and indexing
+        // `self.entries` for it afterwards would panic.
+        let mut rows: HashMap<Key, (Array<f64, FDim>, Array<f64, GDim>)> = keys
+            .iter()
+            .filter_map(|key| self.entries.get(key).map(|v| (key.clone(), v.clone())))
+            .collect();
+
+        let mut seen_in_batch: HashMap<&Key, usize> = HashMap::new();
+        let mut miss_positions = Vec::new();
+        for (i, key) in keys.iter().enumerate() {
+            if rows.contains_key(key) {
+                continue;
+            }
+            seen_in_batch.entry(key).or_insert_with(|| {
+                miss_positions.push(i);
+                i
+            });
+        }
+
+        if !miss_positions.is_empty() {
+            let miss_genes = genes.select(Axis(0), &miss_positions);
+            let miss_population = evaluator.evaluate(miss_genes)?;
+            for (j, &pos) in miss_positions.iter().enumerate() {
+                let fitness_row = miss_population.fitness.select(Axis(0), &[j]);
+                let constraints_row = miss_population.constraints.select(Axis(0), &[j]);
+                self.insert(keys[pos].clone(), fitness_row.clone(), constraints_row.clone());
+                rows.insert(keys[pos].clone(), (fitness_row, constraints_row));
+            }
+        }
+
+        let fitness_rows: Vec<_> = keys.iter().map(|k| rows[k].0.view()).collect();
+        let constraints_rows: Vec<_> = keys.iter().map(|k| rows[k].1.view()).collect();
+        let fitness =
+            concatenate(Axis(0), &fitness_rows).expect("failed to stitch cached fitness rows");
+        let constraints = concatenate(Axis(0), &constraints_rows)
+            .expect("failed to stitch cached constraints rows");
+
+        Ok(Population::new(genes, fitness, constraints))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluator::NoConstraints;
+    use ndarray::{Array1, array};
+
+    fn sum_fitness(genes: &Array2<f64>) -> Array1<f64> {
+        genes.rows().into_iter().map(|row| row.sum()).collect()
+    }
+
+    /// A capacity smaller than one generation's unique-genome count is a
+    /// completely natural configuration (e.g. `capacity == population_size`
+    /// with no survivors yet cached): generation one is all misses, and
+    /// `evaluate` must not panic while stitching results back together.
+    #[test]
+    fn evaluate_with_capacity_below_batch_size_does_not_panic() {
+        let evaluator = Evaluator::new(sum_fitness, NoConstraints);
+        let mut cache = EvaluationCache::new(1e-9, Some(2));
+
+        let genes = array![[1.0, 1.0], [2.0, 2.0], [3.0, 3.0], [4.0, 4.0]];
+        let population = cache
+            .evaluate(&evaluator, genes.clone())
+            .expect("evaluate should not panic when capacity < batch size");
+
+        assert_eq!(population.fitness, array![2.0, 4.0, 6.0, 8.0]);
+        assert!(cache.len() <= 2);
+    }
+
+    #[test]
+    fn evaluate_reuses_cached_row_for_repeated_genome() {
+        let evaluator = Evaluator::new(sum_fitness, NoConstraints);
+        let mut cache = EvaluationCache::new(1e-9, None);
+
+        let first = array![[1.0, 1.0], [2.0, 2.0]];
+        cache.evaluate(&evaluator, first).unwrap();
+        assert_eq!(cache.len(), 2);
+
+        let second = array![[1.0, 1.0], [3.0, 3.0]];
+        let population = cache.evaluate(&evaluator, second).unwrap();
+        assert_eq!(population.fitness, array![2.0, 6.0]);
+        assert_eq!(cache.len(), 3);
+    }
+}
@@ -0,0 +1,133 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+
+use ndarray::{Array, IxDyn};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    genetic::{D12, Population},
+    random::MOORandomGenerator,
+};
+
+/// A serializable snapshot of a [`GeneticAlgorithm`](crate::algorithms::GeneticAlgorithm)
+/// run, capturing the current population's genes/fitness/constraints, the
+/// iteration counter, and the RNG seed/counter, so a long-running search
+/// can be persisted mid-run and restarted deterministically.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Checkpoint {
+    genes_shape: Vec<usize>,
+    genes: Vec<f64>,
+    fitness_shape: Vec<usize>,
+    fitness: Vec<f64>,
+    constraints_shape: Vec<usize>,
+    constraints: Vec<f64>,
+    pub current_iteration: usize,
+    rng_seed: u64,
+    rng_counter: u64,
+}
+
+fn flatten<D: ndarray::Dimension>(arr: &Array<f64, D>) -> (Vec<usize>, Vec<f64>) {
+    (arr.shape().to_vec(), arr.iter().cloned().collect())
+}
+
+fn unflatten<D: D12>(shape: &[usize], data: &[f64]) -> Array<f64, D> {
+    Array::from_shape_vec(IxDyn(shape), data.to_vec())
+        .expect("checkpoint shape does not match stored data")
+        .into_dimensionality::<D>()
+        .expect("checkpoint data has the wrong number of dimensions")
+}
+
+impl Checkpoint {
+    pub fn capture<FDim, GDim>(
+        population: &Population<FDim, GDim>,
+        current_iteration: usize,
+        rng: &MOORandomGenerator,
+    ) -> Self
+    where
+        FDim: D12,
+        GDim: D12,
+    {
+        let (genes_shape, genes) = flatten(&population.genes);
+        let (fitness_shape, fitness) = flatten(&population.fitness);
+        let (constraints_shape, constraints) = flatten(&population.constraints);
+        Self {
+            genes_shape,
+            genes,
+            fitness_shape,
+            fitness,
+            constraints_shape,
+            constraints,
+            current_iteration,
+            rng_seed: rng.seed(),
+            rng_counter: rng.counter(),
+        }
+    }
+
+    pub fn restore<FDim, GDim>(&self) -> (Population<FDim, GDim>, MOORandomGenerator)
+    where
+        FDim: D12,
+        GDim: D12,
+    {
+        let genes = unflatten(&self.genes_shape, &self.genes);
+        let fitness = unflatten(&self.fitness_shape, &self.fitness);
+        let constraints = unflatten(&self.constraints_shape, &self.constraints);
+        let population = Population::new(genes, fitness, constraints);
+        let rng = MOORandomGenerator::from_parts(self.rng_seed, self.rng_counter);
+        (population, rng)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer(writer, self)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let reader = BufReader::new(File::open(path)?);
+        serde_json::from_reader(reader)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::{Array1, Array2, array};
+
+    #[test]
+    fn flatten_unflatten_round_trips_a_2d_array() {
+        let original: Array2<f64> = array![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]];
+        let (shape, data) = flatten(&original);
+        let restored: Array2<f64> = unflatten(&shape, &data);
+        assert_eq!(restored, original);
+    }
+
+    #[test]
+    fn flatten_unflatten_round_trips_a_1d_array() {
+        let original: Array1<f64> = array![1.0, 2.0, 3.0];
+        let (shape, data) = flatten(&original);
+        let restored: Array1<f64> = unflatten(&shape, &data);
+        assert_eq!(restored, original);
+    }
+
+    #[test]
+    fn capture_restore_round_trips_population_and_iteration() {
+        let genes: Array2<f64> = array![[0.0, 1.0], [2.0, 3.0]];
+        let fitness: Array1<f64> = array![1.0, 2.0];
+        let constraints: Array1<f64> = array![0.0, 0.0];
+        let population = Population::new(genes.clone(), fitness.clone(), constraints.clone());
+        let rng = MOORandomGenerator::new_with_seed(42);
+
+        let checkpoint = Checkpoint::capture(&population, 7, &rng);
+        assert_eq!(checkpoint.current_iteration, 7);
+
+        let (restored, restored_rng): (Population<ndarray::Ix1, ndarray::Ix1>, _) =
+            checkpoint.restore();
+        assert_eq!(restored.genes, genes);
+        assert_eq!(restored.fitness, fitness);
+        assert_eq!(restored.constraints, constraints);
+        assert_eq!(restored_rng.seed(), rng.seed());
+        assert_eq!(restored_rng.counter(), rng.counter());
+    }
+}
@@ -4,10 +4,18 @@ use std::sync::{
     Arc,
 };
 
-use ndarray::{Axis, concatenate};
+use ndarray::{Array1, Axis, RemoveAxis, concatenate};
+
+use std::path::PathBuf;
 
 use crate::{
+    algorithms::cache::EvaluationCache,
+    algorithms::checkpoint::Checkpoint,
     algorithms::helpers::{initialization::Initialization, AlgorithmContext, AlgorithmError},
+    algorithms::rates::{MutationRate, SelectionRate},
+    algorithms::stats::{GenerationStats, RunResult, StatsSink},
+    algorithms::stopping::StoppingCriterion,
+    algorithms::surrogate::{Surrogate, select_top_q},
     duplicates::PopulationCleaner,
     evaluator::{ConstraintsFn, Evaluator, FitnessFn},
     genetic::{D12, Population},
@@ -28,7 +36,6 @@ where
     pub population: &'a Population<FDim, GDim>,
 }
 
-#[derive(Debug)]
 pub struct GeneticAlgorithm<S, Sel, Sur, Cross, Mut, F, G, DC>
 where
     S: SamplingOperator,
@@ -37,7 +44,9 @@ where
     Cross: CrossoverOperator,
     Mut: MutationOperator,
     F: FitnessFn,
+    F::Dim: RemoveAxis,
     G: ConstraintsFn,
+    G::Dim: RemoveAxis,
     DC: PopulationCleaner,
 {
     pub population: Option<Population<F::Dim, G::Dim>>,
@@ -48,9 +57,45 @@ where
     pub context: AlgorithmContext,
     verbose: bool,
     rng: MOORandomGenerator,
+    stopping_criteria: Vec<Box<dyn StoppingCriterion<F::Dim, G::Dim>>>,
+    mutation_rate: Option<Box<dyn MutationRate>>,
+    selection_rate: Option<Box<dyn SelectionRate>>,
+    rate_tracker: Option<Box<dyn FnMut(&Population<F::Dim, G::Dim>) -> f64>>,
+    rate_history: Vec<f64>,
+    fitness_cache: Option<EvaluationCache<F::Dim, G::Dim>>,
+    checkpoint: Option<(PathBuf, usize)>,
+    stats_history: Vec<GenerationStats>,
+    stats_sink: Option<Box<dyn StatsSink>>,
+    hypervolume_reference: Option<Array1<f64>>,
+    surrogate: Option<(Box<dyn Surrogate<F::Dim>>, usize)>,
     phantom: PhantomData<S>,
 }
 
+impl<S, Sel, Sur, Cross, Mut, F, G, DC> std::fmt::Debug
+    for GeneticAlgorithm<S, Sel, Sur, Cross, Mut, F, G, DC>
+where
+    S: SamplingOperator,
+    Sel: SelectionOperator<FDim = F::Dim>,
+    Sur: SurvivalOperator<FDim = F::Dim>,
+    Cross: CrossoverOperator,
+    Mut: MutationOperator,
+    F: FitnessFn,
+    F::Dim: RemoveAxis,
+    G: ConstraintsFn,
+    G::Dim: RemoveAxis,
+    DC: PopulationCleaner,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GeneticAlgorithm")
+            .field("context", &self.context)
+            .field("verbose", &self.verbose)
+            .field("stopping_criteria_count", &self.stopping_criteria.len())
+            .field("mutation_rate", &self.mutation_rate)
+            .field("selection_rate", &self.selection_rate)
+            .finish()
+    }
+}
+
 impl<S, Sel, Sur, Cross, Mut, F, G, DC> GeneticAlgorithm<S, Sel, Sur, Cross, Mut, F, G, DC>
 where
     S: SamplingOperator,
@@ -59,7 +104,9 @@ where
     Cross: CrossoverOperator,
     Mut: MutationOperator,
     F: FitnessFn,
+    F::Dim: RemoveAxis,
     G: ConstraintsFn,
+    G::Dim: RemoveAxis,
     DC: PopulationCleaner,
 {
     pub fn new(
@@ -81,12 +128,160 @@ where
             context: context,
             verbose: verbose,
             rng: rng,
+            stopping_criteria: Vec::new(),
+            mutation_rate: None,
+            selection_rate: None,
+            rate_tracker: None,
+            rate_history: Vec::new(),
+            fitness_cache: None,
+            checkpoint: None,
+            stats_history: Vec::new(),
+            stats_sink: None,
+            hypervolume_reference: None,
+            surrogate: None,
             phantom: PhantomData,
         }
     }
 
+    /// Builds a `GeneticAlgorithm` resuming from a previously saved
+    /// [`Checkpoint`]: the loaded population and iteration counter seed the
+    /// run, so [`Initialization::initialize`] is skipped in
+    /// `run_cancellable`. Exposed on the algorithm builders as
+    /// `.resume_from(path)`.
+    pub fn resume_from(
+        path: impl AsRef<std::path::Path>,
+        sampler: S,
+        survivor: Sur,
+        evolve: Evolve<Sel, Cross, Mut, DC>,
+        evaluator: Evaluator<F, G>,
+        mut context: AlgorithmContext,
+        verbose: bool,
+    ) -> std::io::Result<Self> {
+        let checkpoint = Checkpoint::load(path)?;
+        let (population, rng) = checkpoint.restore::<F::Dim, G::Dim>();
+        // `checkpoint.current_iteration` is the 0-based index of the last
+        // *completed* generation (see the `set_current_iteration` call at
+        // the end of `run_cancellable`'s loop body); resuming must start
+        // one generation past that, or the loaded population gets evolved
+        // again under a duplicate generation label.
+        context.set_current_iteration(checkpoint.current_iteration + 1);
+        Ok(Self::new(
+            Some(population),
+            sampler,
+            survivor,
+            evolve,
+            evaluator,
+            context,
+            verbose,
+            rng,
+        ))
+    }
+
+    /// Writes a [`Checkpoint`] of the current population, iteration count
+    /// and RNG state to `path`.
+    pub fn save_checkpoint(&self, path: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+        let population = self
+            .population
+            .as_ref()
+            .expect("cannot checkpoint before the first generation has run");
+        Checkpoint::capture(population, self.context.current_iteration, &self.rng).save(path)
+    }
+
+    /// Writes a checkpoint to `path` every `every` generations (plus a
+    /// final snapshot when the run is cancelled).
+    pub fn enable_periodic_checkpoint(&mut self, path: PathBuf, every: usize) {
+        self.checkpoint = Some((path, every));
+    }
+
+    /// Registers an additional early-stopping rule. All registered criteria
+    /// are consulted at the end of every generation; the run stops as soon
+    /// as any one of them returns `true`. Also exposed on the algorithm
+    /// builders as `.stopping_criterion(...)`, alongside `num_iterations`.
+    pub fn add_stopping_criterion(
+        &mut self,
+        criterion: Box<dyn StoppingCriterion<F::Dim, G::Dim>>,
+    ) {
+        self.stopping_criteria.push(criterion);
+    }
+
+    /// Installs a [`MutationRate`] that is recomputed every generation and
+    /// pushed into the `Evolve` operator ahead of the mutation step,
+    /// replacing a mutation rate fixed once at build time. Requires a
+    /// tracker (see [`Self::set_rate_tracker`]) when the rate depends on
+    /// fitness progress rather than only on the generation index. Also
+    /// exposed on the algorithm builders as `.adaptive_mutation_rate(...)`.
+    pub fn set_mutation_rate(&mut self, rate: Box<dyn MutationRate>) {
+        self.mutation_rate = Some(rate);
+    }
+
+    /// Installs a [`SelectionRate`], the parallel counterpart of
+    /// [`Self::set_mutation_rate`] for selection pressure. Also exposed on
+    /// the algorithm builders as `.adaptive_selection_rate(...)`.
+    pub fn set_selection_rate(&mut self, rate: Box<dyn SelectionRate>) {
+        self.selection_rate = Some(rate);
+    }
+
+    /// Supplies the function used to extract the scalar best-fitness (or
+    /// chosen indicator, for MOO) value tracked by slope-adaptive rates.
+    /// Also exposed on the algorithm builders as `.rate_tracker(...)`.
+    pub fn set_rate_tracker(
+        &mut self,
+        tracker: Box<dyn FnMut(&Population<F::Dim, G::Dim>) -> f64>,
+    ) {
+        self.rate_tracker = Some(tracker);
+    }
+
+    /// Enables the per-genotype fitness/constraints cache: rows of the
+    /// combined parent+offspring matrix whose quantized genome (within
+    /// `tolerance`, matching [`crate::duplicates::CloseDuplicatesCleaner`])
+    /// was already evaluated are served from the cache instead of being
+    /// re-evaluated. `capacity` bounds the number of entries kept, evicted
+    /// first-in-first-out once exceeded. Also exposed on the algorithm
+    /// builders as `.enable_fitness_cache(...)`.
+    pub fn enable_fitness_cache(&mut self, tolerance: f64, capacity: Option<usize>) {
+        self.fitness_cache = Some(EvaluationCache::new(tolerance, capacity));
+    }
+
+    /// Streams each generation's [`GenerationStats`] to `sink` as soon as
+    /// it is produced, in addition to accumulating it in the returned
+    /// [`RunResult`].
+    pub fn set_stats_sink(&mut self, sink: Box<dyn StatsSink>) {
+        self.stats_sink = Some(sink);
+    }
+
+    /// Configures the reference point used to report the hypervolume
+    /// indicator in each generation's [`GenerationStats`].
+    pub fn set_hypervolume_reference(&mut self, reference: Array1<f64>) {
+        self.hypervolume_reference = Some(reference);
+    }
+
+    /// Installs a [`Surrogate`] that pre-screens offspring each generation:
+    /// it is refit on the current (truly-evaluated) population, used to
+    /// predict every offspring's fitness, and only the `num_screened` most
+    /// promising candidates are passed on to the real evaluator. Also
+    /// exposed on the algorithm builders as `.surrogate(...)`.
+    pub fn set_surrogate(&mut self, surrogate: Box<dyn Surrogate<F::Dim>>, num_screened: usize) {
+        self.surrogate = Some((surrogate, num_screened));
+    }
+
     fn next(&mut self) -> Result<(), AlgorithmError> {
         let ref_pop = self.population.as_ref().unwrap();
+
+        if self.mutation_rate.is_some() || self.selection_rate.is_some() {
+            if let Some(tracker) = &mut self.rate_tracker {
+                self.rate_history.push(tracker(ref_pop));
+            }
+            let generation = self.context.current_iteration;
+            if let Some(mutation_rate) = &mut self.mutation_rate {
+                let rate = mutation_rate.rate(generation, &self.rate_history);
+                self.evolve.set_mutation_rate(rate);
+            }
+            if let Some(selection_rate) = &mut self.selection_rate {
+                let rate = selection_rate.rate(generation, &self.rate_history);
+                self.evolve.set_selection_rate(rate);
+            }
+        }
+
         // Obtain offspring genes.
         let offspring_genes = self
             .evolve
@@ -102,11 +297,26 @@ where
             self.context.num_vars
         );
 
+        // Pre-screen offspring through the surrogate, if configured, so only
+        // the most promising candidates reach the real (expensive) evaluator.
+        let offspring_genes = if let Some((surrogate, num_screened)) = &mut self.surrogate {
+            surrogate.fit(&ref_pop.genes, &ref_pop.fitness);
+            let predicted = surrogate.predict(&offspring_genes);
+            let kept = select_top_q(&predicted, *num_screened);
+            offspring_genes.select(Axis(0), &kept)
+        } else {
+            offspring_genes
+        };
+
         // Combine the current population with the offspring.
         let combined_genes = concatenate(Axis(0), &[ref_pop.genes.view(), offspring_genes.view()])
             .expect("Failed to concatenate current population genes with offspring genes");
-        // Evaluate the fitness and constraints and create Population
-        let evaluated_population = self.evaluator.evaluate(combined_genes)?;
+        // Evaluate the fitness and constraints and create Population, reusing
+        // cached results for already-seen genomes when the cache is enabled.
+        let evaluated_population = match &mut self.fitness_cache {
+            Some(cache) => cache.evaluate(&self.evaluator, combined_genes)?,
+            None => self.evaluator.evaluate(combined_genes)?,
+        };
 
         // Select survivors to the next iteration population
         let survivors = self.survivor.operate(
@@ -120,7 +330,7 @@ where
         Ok(())
     }
 
-    pub fn run(&mut self) -> Result<(), AlgorithmError> {
+    pub fn run(&mut self) -> Result<RunResult<F::Dim, G::Dim>, AlgorithmError> {
         self.run_cancellable::<fn(IterationData<F::Dim, G::Dim>)>(
             Arc::new(AtomicBool::new(false)),
             None,
@@ -131,27 +341,39 @@ where
         &mut self,
         token: Arc<AtomicBool>,
         mut callback: Option<C>,
-    ) -> Result<(), AlgorithmError>
+    ) -> Result<RunResult<F::Dim, G::Dim>, AlgorithmError>
     where
         C: FnMut(IterationData<F::Dim, G::Dim>),
     {
-        // Create the first Population
-        let initial_population = Initialization::initialize(
-            &self.sampler,
-            &mut self.survivor,
-            &self.evaluator,
-            &self.evolve.duplicates_cleaner,
-            &mut self.rng,
-            &self.context,
-        )?;
-        // Update population attribute
-        self.population = Some(initial_population);
+        // Create the first Population, unless one was already loaded via
+        // `resume_from`, in which case initialization is skipped entirely.
+        if self.population.is_none() {
+            let initial_population = Initialization::initialize(
+                &self.sampler,
+                &mut self.survivor,
+                &self.evaluator,
+                &self.evolve.duplicates_cleaner,
+                &mut self.rng,
+                &self.context,
+            )?;
+            self.population = Some(initial_population);
+        }
 
-        for current_iter in 0..self.context.num_iterations {
+        for current_iter in self.context.current_iteration..self.context.num_iterations {
             if token.load(Ordering::Relaxed) {
                 if self.verbose {
                     println!("Algorithm cancelled at iteration {}", current_iter);
                 }
+                if let Some((path, _)) = &self.checkpoint {
+                    let path = path.clone();
+                    // Generation `current_iter` has not run; record the one
+                    // before it as the last completed generation.
+                    self.context
+                        .set_current_iteration(current_iter.saturating_sub(1));
+                    if let Err(err) = self.save_checkpoint(&path) {
+                        println!("Warning: failed to write checkpoint on cancellation: {}", err);
+                    }
+                }
                 break;
             }
 
@@ -170,6 +392,41 @@ where
                         };
                         cb(data);
                     }
+
+                    let population = self.population.as_ref().unwrap();
+                    let stats = GenerationStats::capture(
+                        population,
+                        current_iter + 1,
+                        self.hypervolume_reference.as_ref(),
+                    );
+                    if let Some(sink) = &mut self.stats_sink {
+                        if let Err(err) = sink.write(&stats) {
+                            println!("Warning: failed to write generation stats: {}", err);
+                        }
+                    }
+                    self.stats_history.push(stats);
+
+                    let stop = self
+                        .stopping_criteria
+                        .iter_mut()
+                        .any(|criterion| criterion.should_stop(population, current_iter + 1));
+                    if stop {
+                        if self.verbose {
+                            println!("Stopping criterion met at iteration {}", current_iter + 1);
+                        }
+                        self.context.set_current_iteration(current_iter);
+                        break;
+                    }
+
+                    if let Some((path, every)) = &self.checkpoint {
+                        if *every > 0 && (current_iter + 1) % every == 0 {
+                            let path = path.clone();
+                            self.context.set_current_iteration(current_iter);
+                            if let Err(err) = self.save_checkpoint(&path) {
+                                println!("Warning: failed to write checkpoint: {}", err);
+                            }
+                        }
+                    }
                 }
                 Err(AlgorithmError::Evolve(err @ EvolveError::EmptyMatingResult)) => {
                     println!("Warning: {}. Terminating the algorithm early.", err);
@@ -179,6 +436,10 @@ where
             }
             self.context.set_current_iteration(current_iter);
         }
-        Ok(())
+
+        Ok(RunResult {
+            population: self.population.as_ref().unwrap().clone(),
+            history: std::mem::take(&mut self.stats_history),
+        })
     }
 }
@@ -1,12 +1,27 @@
 mod builder;
+mod cache;
+mod checkpoint;
 mod ga;
 pub(crate) mod helpers;
 mod macros;
 mod moo;
+mod rates;
 mod soo;
+mod stats;
+mod stopping;
+mod surrogate;
 
 pub use builder::{AlgorithmBuilder, AlgorithmBuilderError};
+pub use cache::EvaluationCache;
+pub use checkpoint::Checkpoint;
 pub use ga::{GeneticAlgorithm, IterationData};
+pub use rates::{ConstantRate, LinearRate, MutationRate, SelectionRate, SlopeAdaptiveRate};
+pub use stats::{CsvStatsSink, GenerationStats, JsonStatsSink, ObjectiveStats, RunResult, StatsSink};
+pub use surrogate::{KnnSurrogate, Surrogate};
+pub use stopping::{
+    StagnationStoppingCriterion, StoppingCriterion, TargetFitnessStoppingCriterion,
+    TimeBudgetStoppingCriterion,
+};
 pub use moo::agemoea::{AgeMoea, AgeMoeaBuilder};
 pub use moo::ibea::{Ibea, IbeaBuilder};
 pub use moo::nsga2::{Nsga2, Nsga2Builder};
@@ -0,0 +1,186 @@
+use std::fmt;
+
+/// Recomputed every generation and pushed into the [`Evolve`](crate::operators::Evolve)
+/// operator ahead of the mutation step, in place of a mutation rate fixed
+/// once at build time.
+pub trait MutationRate: fmt::Debug {
+    /// Computes the mutation rate for `generation`, given the history of
+    /// tracked best-fitness values observed so far (oldest first).
+    fn rate(&mut self, generation: usize, history: &[f64]) -> f64;
+}
+
+/// Parallel counterpart of [`MutationRate`] for the selection pressure
+/// applied by the [`Evolve`](crate::operators::Evolve) operator.
+pub trait SelectionRate: fmt::Debug {
+    /// Computes the selection rate for `generation`, given the history of
+    /// tracked best-fitness values observed so far (oldest first).
+    fn rate(&mut self, generation: usize, history: &[f64]) -> f64;
+}
+
+/// A rate that never changes across generations, matching the previous
+/// fixed-at-build-time behaviour.
+#[derive(Debug, Clone, Copy)]
+pub struct ConstantRate(pub f64);
+
+impl MutationRate for ConstantRate {
+    fn rate(&mut self, _generation: usize, _history: &[f64]) -> f64 {
+        self.0
+    }
+}
+
+impl SelectionRate for ConstantRate {
+    fn rate(&mut self, _generation: usize, _history: &[f64]) -> f64 {
+        self.0
+    }
+}
+
+/// A rate that interpolates linearly from `start` to `end` over
+/// `num_iterations` generations, then holds at `end`.
+#[derive(Debug, Clone, Copy)]
+pub struct LinearRate {
+    pub start: f64,
+    pub end: f64,
+    pub num_iterations: usize,
+}
+
+impl LinearRate {
+    pub fn new(start: f64, end: f64, num_iterations: usize) -> Self {
+        Self {
+            start,
+            end,
+            num_iterations,
+        }
+    }
+
+    fn interpolate(&self, generation: usize) -> f64 {
+        if self.num_iterations == 0 {
+            return self.end;
+        }
+        let t = (generation as f64 / self.num_iterations as f64).min(1.0);
+        self.start + (self.end - self.start) * t
+    }
+}
+
+impl MutationRate for LinearRate {
+    fn rate(&mut self, generation: usize, _history: &[f64]) -> f64 {
+        self.interpolate(generation)
+    }
+}
+
+impl SelectionRate for LinearRate {
+    fn rate(&mut self, generation: usize, _history: &[f64]) -> f64 {
+        self.interpolate(generation)
+    }
+}
+
+/// A rate that self-tunes from the progress slope of the tracked
+/// best-fitness history: it rises when progress over the trailing
+/// `window` generations flattens and relaxes during rapid improvement.
+///
+/// The slope `s` is obtained from a least-squares fit of
+/// `(generation_index, best_fitness)` over the trailing window, and the
+/// rate is `clamp(base_rate * (1 + coef / (|s| + thr)), min, max)`.
+#[derive(Debug, Clone, Copy)]
+pub struct SlopeAdaptiveRate {
+    pub window: usize,
+    pub base_rate: f64,
+    pub coef: f64,
+    pub thr: f64,
+    pub min: f64,
+    pub max: f64,
+}
+
+impl SlopeAdaptiveRate {
+    pub fn new(window: usize, base_rate: f64, coef: f64, thr: f64, min: f64, max: f64) -> Self {
+        Self {
+            window,
+            base_rate,
+            coef,
+            thr,
+            min,
+            max,
+        }
+    }
+
+    fn slope(&self, history: &[f64]) -> f64 {
+        let tail = &history[history.len().saturating_sub(self.window)..];
+        if tail.len() < 2 {
+            return 0.0;
+        }
+        let n = tail.len() as f64;
+        let xs: Vec<f64> = (0..tail.len()).map(|i| i as f64).collect();
+        let x_mean = xs.iter().sum::<f64>() / n;
+        let y_mean = tail.iter().sum::<f64>() / n;
+        let mut num = 0.0;
+        let mut den = 0.0;
+        for (x, y) in xs.iter().zip(tail.iter()) {
+            num += (x - x_mean) * (y - y_mean);
+            den += (x - x_mean) * (x - x_mean);
+        }
+        if den == 0.0 { 0.0 } else { num / den }
+    }
+
+    fn compute(&self, history: &[f64]) -> f64 {
+        let s = self.slope(history);
+        let rate = self.base_rate * (1.0 + self.coef / (s.abs() + self.thr));
+        rate.clamp(self.min, self.max)
+    }
+}
+
+impl MutationRate for SlopeAdaptiveRate {
+    fn rate(&mut self, _generation: usize, history: &[f64]) -> f64 {
+        self.compute(history)
+    }
+}
+
+impl SelectionRate for SlopeAdaptiveRate {
+    fn rate(&mut self, _generation: usize, history: &[f64]) -> f64 {
+        self.compute(history)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flat_history_has_zero_slope_and_falls_back_to_base_rate_scaled_by_thr() {
+        let rate = SlopeAdaptiveRate::new(4, 0.1, 1.0, 0.5, 0.0, 1.0);
+        // Zero progress slope: rate = base_rate * (1 + coef / thr) = 0.1 * 3 = 0.3.
+        assert!((rate.compute(&[5.0, 5.0, 5.0, 5.0]) - 0.3).abs() < 1e-9);
+    }
+
+    #[test]
+    fn steep_improving_slope_relaxes_the_rate_towards_base_rate() {
+        let rate = SlopeAdaptiveRate::new(4, 0.1, 1.0, 0.5, 0.0, 1.0);
+        // Slope of 2 over a steadily improving history: rate = 0.1 * (1 + 1/2.5) = 0.14.
+        assert!((rate.compute(&[0.0, 2.0, 4.0, 6.0]) - 0.14).abs() < 1e-9);
+    }
+
+    #[test]
+    fn window_only_considers_the_trailing_history() {
+        let rate = SlopeAdaptiveRate::new(3, 0.1, 1.0, 0.5, 0.0, 1.0);
+        // Only the trailing 3 values ([0.0, 2.0, 4.0]) feed the slope
+        // computation; the leading 100.0s are outside the window and must
+        // not affect the result.
+        assert!((rate.compute(&[100.0, 100.0, 0.0, 2.0, 4.0]) - 0.14).abs() < 1e-9);
+    }
+
+    #[test]
+    fn history_shorter_than_two_points_has_zero_slope() {
+        let rate = SlopeAdaptiveRate::new(4, 0.1, 1.0, 0.5, 0.0, 1.0);
+        assert!((rate.compute(&[3.0]) - 0.3).abs() < 1e-9);
+    }
+
+    #[test]
+    fn rate_is_clamped_to_max() {
+        let rate = SlopeAdaptiveRate::new(4, 0.1, 10.0, 0.01, 0.0, 0.5);
+        assert_eq!(rate.compute(&[5.0, 5.0, 5.0, 5.0]), 0.5);
+    }
+
+    #[test]
+    fn rate_is_clamped_to_min() {
+        let rate = SlopeAdaptiveRate::new(4, 0.01, 0.0, 0.5, 0.05, 0.5);
+        assert_eq!(rate.compute(&[5.0, 5.0, 5.0, 5.0]), 0.05);
+    }
+}
@@ -0,0 +1,341 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use ndarray::{Array1, Array2};
+
+use crate::{
+    genetic::{D12, Population},
+    hypervolume::Hypervolume,
+};
+
+/// Best/mean/std summary of a single objective across a generation.
+#[derive(Debug, Clone, Copy)]
+pub struct ObjectiveStats {
+    pub best: f64,
+    pub mean: f64,
+    pub std: f64,
+}
+
+/// Per-generation bookkeeping accumulated by [`GeneticAlgorithm::run`](
+/// crate::algorithms::GeneticAlgorithm::run) / `run_cancellable`, returned
+/// as part of [`RunResult`] instead of being reimplemented inside user
+/// callbacks.
+#[derive(Debug, Clone)]
+pub struct GenerationStats {
+    pub iteration: usize,
+    pub population_size: usize,
+    pub num_non_dominated: usize,
+    pub objectives: Vec<ObjectiveStats>,
+    pub constraint_violations: usize,
+    pub diversity: f64,
+    pub hypervolume: Option<f64>,
+}
+
+/// Flattens a generic `Array<f64, D>` to `(nrows, ncols, row_major_values)`,
+/// treating a 1‑D (SOO) array as a single-column matrix.
+fn flatten_2d<D: ndarray::Dimension>(arr: &ndarray::ArrayView<f64, D>) -> (usize, usize, Vec<f64>) {
+    let shape = arr.shape();
+    let (nrows, ncols) = match shape.len() {
+        1 => (shape[0], 1),
+        _ => (shape[0], shape[1]),
+    };
+    (nrows, ncols, arr.iter().cloned().collect())
+}
+
+fn non_dominated_count(nrows: usize, ncols: usize, values: &[f64]) -> usize {
+    let row = |i: usize| &values[i * ncols..(i + 1) * ncols];
+    (0..nrows)
+        .filter(|&i| {
+            let candidate = row(i);
+            !(0..nrows).any(|j| {
+                j != i
+                    && row(j).iter().zip(candidate).all(|(a, b)| a <= b)
+                    && row(j).iter().zip(candidate).any(|(a, b)| a < b)
+            })
+        })
+        .count()
+}
+
+impl GenerationStats {
+    pub(crate) fn capture<FDim, GDim>(
+        population: &Population<FDim, GDim>,
+        iteration: usize,
+        hypervolume_reference: Option<&Array1<f64>>,
+    ) -> Self
+    where
+        FDim: D12,
+        GDim: D12,
+    {
+        let (fit_rows, fit_cols, fit_values) = flatten_2d(&population.fitness.view());
+        let (_, con_cols, con_values) = flatten_2d(&population.constraints.view());
+
+        let objectives = (0..fit_cols)
+            .map(|c| {
+                let column: Vec<f64> = (0..fit_rows).map(|r| fit_values[r * fit_cols + c]).collect();
+                let best = column.iter().cloned().fold(f64::INFINITY, f64::min);
+                let mean = column.iter().sum::<f64>() / fit_rows as f64;
+                let variance =
+                    column.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / fit_rows as f64;
+                ObjectiveStats {
+                    best,
+                    mean,
+                    std: variance.sqrt(),
+                }
+            })
+            .collect();
+
+        let constraint_violations = (0..fit_rows)
+            .filter(|&r| (0..con_cols).any(|c| con_values[r * con_cols + c] > 0.0))
+            .count();
+
+        let genes = &population.genes;
+        let diversity = genes
+            .axis_iter(ndarray::Axis(1))
+            .map(|column| {
+                let mean = column.mean().unwrap_or(0.0);
+                (column.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / column.len() as f64)
+                    .sqrt()
+            })
+            .sum::<f64>()
+            / genes.ncols() as f64;
+
+        let hypervolume = hypervolume_reference.map(|reference| {
+            let front = Array2::from_shape_vec((fit_rows, fit_cols), fit_values.clone())
+                .expect("fitness values do not match their own reported shape");
+            Hypervolume::compute(&front, reference)
+        });
+
+        Self {
+            iteration,
+            population_size: fit_rows,
+            num_non_dominated: non_dominated_count(fit_rows, fit_cols, &fit_values),
+            objectives,
+            constraint_violations,
+            diversity,
+            hypervolume,
+        }
+    }
+}
+
+/// A sink that each [`GenerationStats`] row is streamed to as soon as it is
+/// produced, so users don't have to reimplement bookkeeping inside their
+/// own callback to plot a run offline.
+pub trait StatsSink {
+    fn write(&mut self, stats: &GenerationStats) -> std::io::Result<()>;
+}
+
+/// Streams one CSV row per generation.
+pub struct CsvStatsSink {
+    writer: BufWriter<File>,
+    header_written: bool,
+}
+
+impl CsvStatsSink {
+    pub fn create(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        Ok(Self {
+            writer: BufWriter::new(File::create(path)?),
+            header_written: false,
+        })
+    }
+}
+
+impl StatsSink for CsvStatsSink {
+    fn write(&mut self, stats: &GenerationStats) -> std::io::Result<()> {
+        if !self.header_written {
+            write!(
+                self.writer,
+                "iteration,population_size,num_non_dominated,constraint_violations,diversity,hypervolume"
+            )?;
+            for i in 0..stats.objectives.len() {
+                write!(self.writer, ",objective_{i}_best,objective_{i}_mean,objective_{i}_std")?;
+            }
+            writeln!(self.writer)?;
+            self.header_written = true;
+        }
+        write!(
+            self.writer,
+            "{},{},{},{},{},{}",
+            stats.iteration,
+            stats.population_size,
+            stats.num_non_dominated,
+            stats.constraint_violations,
+            stats.diversity,
+            stats
+                .hypervolume
+                .map(|h| h.to_string())
+                .unwrap_or_default(),
+        )?;
+        for objective in &stats.objectives {
+            write!(
+                self.writer,
+                ",{},{},{}",
+                objective.best, objective.mean, objective.std
+            )?;
+        }
+        writeln!(self.writer)?;
+        self.writer.flush()
+    }
+}
+
+/// Streams one JSON object per generation (newline-delimited JSON).
+pub struct JsonStatsSink {
+    writer: BufWriter<File>,
+}
+
+impl JsonStatsSink {
+    pub fn create(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        Ok(Self {
+            writer: BufWriter::new(File::create(path)?),
+        })
+    }
+}
+
+#[derive(serde::Serialize)]
+struct GenerationStatsJson<'a> {
+    iteration: usize,
+    population_size: usize,
+    num_non_dominated: usize,
+    constraint_violations: usize,
+    diversity: f64,
+    hypervolume: Option<f64>,
+    objectives: &'a [ObjectiveStats],
+}
+
+impl serde::Serialize for ObjectiveStats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("ObjectiveStats", 3)?;
+        s.serialize_field("best", &self.best)?;
+        s.serialize_field("mean", &self.mean)?;
+        s.serialize_field("std", &self.std)?;
+        s.end()
+    }
+}
+
+impl StatsSink for JsonStatsSink {
+    fn write(&mut self, stats: &GenerationStats) -> std::io::Result<()> {
+        let row = GenerationStatsJson {
+            iteration: stats.iteration,
+            population_size: stats.population_size,
+            num_non_dominated: stats.num_non_dominated,
+            constraint_violations: stats.constraint_violations,
+            diversity: stats.diversity,
+            hypervolume: stats.hypervolume,
+            objectives: &stats.objectives,
+        };
+        serde_json::to_writer(&mut self.writer, &row)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        writeln!(self.writer)?;
+        self.writer.flush()
+    }
+}
+
+/// Returned from [`GeneticAlgorithm::run`](crate::algorithms::GeneticAlgorithm::run) /
+/// `run_cancellable`: the final population alongside the full per-generation
+/// history.
+pub struct RunResult<FDim, GDim>
+where
+    FDim: D12,
+    GDim: D12,
+{
+    pub population: Population<FDim, GDim>,
+    pub history: Vec<GenerationStats>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+
+    fn sample_population() -> Population<ndarray::Ix2, ndarray::Ix2> {
+        let genes = array![[0.0], [1.0], [2.0]];
+        let fitness = array![[1.0, 4.0], [2.0, 2.0], [3.0, 3.0]];
+        let constraints = array![[0.0], [0.1], [0.0]];
+        Population::new(genes, fitness, constraints)
+    }
+
+    #[test]
+    fn non_dominated_count_excludes_dominated_rows() {
+        // Row [3.0, 3.0] is dominated by [2.0, 2.0] (both coordinates
+        // smaller); the other two rows dominate neither one another.
+        let fit_values = vec![1.0, 4.0, 2.0, 2.0, 3.0, 3.0];
+        assert_eq!(non_dominated_count(3, 2, &fit_values), 2);
+    }
+
+    #[test]
+    fn capture_summarizes_objectives_constraints_and_diversity() {
+        let stats = GenerationStats::capture(&sample_population(), 5, None);
+
+        assert_eq!(stats.iteration, 5);
+        assert_eq!(stats.population_size, 3);
+        assert_eq!(stats.num_non_dominated, 2);
+        assert_eq!(stats.constraint_violations, 1);
+        assert!(stats.hypervolume.is_none());
+
+        assert_eq!(stats.objectives.len(), 2);
+        assert!((stats.objectives[0].best - 1.0).abs() < 1e-9);
+        assert!((stats.objectives[0].mean - 2.0).abs() < 1e-9);
+        assert!((stats.objectives[0].std - (2.0_f64 / 3.0).sqrt()).abs() < 1e-9);
+        assert!((stats.objectives[1].best - 2.0).abs() < 1e-9);
+        assert!((stats.objectives[1].mean - 3.0).abs() < 1e-9);
+        assert!((stats.objectives[1].std - (2.0_f64 / 3.0).sqrt()).abs() < 1e-9);
+
+        assert!((stats.diversity - (2.0_f64 / 3.0).sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn capture_computes_hypervolume_only_when_a_reference_is_given() {
+        let reference = array![4.0, 4.0];
+        let stats = GenerationStats::capture(&sample_population(), 0, Some(&reference));
+        assert!(stats.hypervolume.is_some());
+    }
+
+    fn unique_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("moors_stats_test_{name}_{}.out", std::process::id()))
+    }
+
+    #[test]
+    fn csv_sink_writes_a_header_once_followed_by_one_row_per_generation() {
+        let path = unique_path("csv_sink");
+        {
+            let mut sink = CsvStatsSink::create(&path).unwrap();
+            sink.write(&GenerationStats::capture(&sample_population(), 0, None))
+                .unwrap();
+            sink.write(&GenerationStats::capture(&sample_population(), 1, None))
+                .unwrap();
+        }
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("iteration,population_size"));
+        assert!(lines[1].starts_with("0,3,2,1,"));
+        assert!(lines[2].starts_with("1,3,2,1,"));
+    }
+
+    #[test]
+    fn json_sink_writes_one_object_per_line() {
+        let path = unique_path("json_sink");
+        {
+            let mut sink = JsonStatsSink::create(&path).unwrap();
+            sink.write(&GenerationStats::capture(&sample_population(), 0, None))
+                .unwrap();
+            sink.write(&GenerationStats::capture(&sample_population(), 1, None))
+                .unwrap();
+        }
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(lines.len(), 2);
+        let first: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(first["iteration"], 0);
+        assert_eq!(first["num_non_dominated"], 2);
+        assert_eq!(first["objectives"].as_array().unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,237 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use approx::relative_eq;
+
+use crate::genetic::{D12, Population};
+
+/// A pluggable early-stopping rule consulted at the end of every
+/// [`GeneticAlgorithm::next`](crate::algorithms::GeneticAlgorithm) call.
+///
+/// Implementors observe the freshly produced [`Population`] for the
+/// current generation and decide whether the run loop should break before
+/// `context.num_iterations` is reached.
+pub trait StoppingCriterion<FDim, GDim>: fmt::Debug
+where
+    FDim: D12,
+    GDim: D12,
+{
+    /// Returns `true` if the algorithm should stop after this generation.
+    fn should_stop(&mut self, population: &Population<FDim, GDim>, iteration: usize) -> bool;
+}
+
+/// Stops the search once the tracked value fails to improve by more than
+/// `epsilon` (relative tolerance) for `limit` consecutive generations.
+///
+/// `extract` computes the value to track from the current population: the
+/// best fitness for single-objective problems, or a chosen scalarization /
+/// indicator (e.g. [`crate::hypervolume::Hypervolume`]) for MOO problems.
+pub struct StagnationStoppingCriterion<FDim, GDim, Extract>
+where
+    Extract: FnMut(&Population<FDim, GDim>) -> f64,
+{
+    extract: Extract,
+    epsilon: f64,
+    limit: usize,
+    last_value: Option<f64>,
+    stagnation_count: usize,
+    _phantom: std::marker::PhantomData<(FDim, GDim)>,
+}
+
+impl<FDim, GDim, Extract> StagnationStoppingCriterion<FDim, GDim, Extract>
+where
+    Extract: FnMut(&Population<FDim, GDim>) -> f64,
+{
+    pub fn new(extract: Extract, epsilon: f64, limit: usize) -> Self {
+        Self {
+            extract,
+            epsilon,
+            limit,
+            last_value: None,
+            stagnation_count: 0,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<FDim, GDim, Extract> fmt::Debug for StagnationStoppingCriterion<FDim, GDim, Extract>
+where
+    Extract: FnMut(&Population<FDim, GDim>) -> f64,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StagnationStoppingCriterion")
+            .field("epsilon", &self.epsilon)
+            .field("limit", &self.limit)
+            .field("last_value", &self.last_value)
+            .field("stagnation_count", &self.stagnation_count)
+            .finish()
+    }
+}
+
+impl<FDim, GDim, Extract> StoppingCriterion<FDim, GDim>
+    for StagnationStoppingCriterion<FDim, GDim, Extract>
+where
+    FDim: D12,
+    GDim: D12,
+    Extract: FnMut(&Population<FDim, GDim>) -> f64,
+{
+    fn should_stop(&mut self, population: &Population<FDim, GDim>, _iteration: usize) -> bool {
+        let best = (self.extract)(population);
+        match self.last_value {
+            Some(last) if relative_eq!(best, last, epsilon = 0.0, max_relative = self.epsilon) => {
+                self.stagnation_count += 1;
+            }
+            _ => {
+                self.stagnation_count = 0;
+            }
+        }
+        self.last_value = Some(best);
+        self.stagnation_count >= self.limit
+    }
+}
+
+/// Stops the search as soon as the tracked value reaches `target` (or
+/// surpasses it, for a minimization-style value where lower is better).
+pub struct TargetFitnessStoppingCriterion<FDim, GDim, Extract>
+where
+    Extract: FnMut(&Population<FDim, GDim>) -> f64,
+{
+    extract: Extract,
+    target: f64,
+    _phantom: std::marker::PhantomData<(FDim, GDim)>,
+}
+
+impl<FDim, GDim, Extract> TargetFitnessStoppingCriterion<FDim, GDim, Extract>
+where
+    Extract: FnMut(&Population<FDim, GDim>) -> f64,
+{
+    pub fn new(extract: Extract, target: f64) -> Self {
+        Self {
+            extract,
+            target,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<FDim, GDim, Extract> fmt::Debug for TargetFitnessStoppingCriterion<FDim, GDim, Extract>
+where
+    Extract: FnMut(&Population<FDim, GDim>) -> f64,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TargetFitnessStoppingCriterion")
+            .field("target", &self.target)
+            .finish()
+    }
+}
+
+impl<FDim, GDim, Extract> StoppingCriterion<FDim, GDim>
+    for TargetFitnessStoppingCriterion<FDim, GDim, Extract>
+where
+    FDim: D12,
+    GDim: D12,
+    Extract: FnMut(&Population<FDim, GDim>) -> f64,
+{
+    fn should_stop(&mut self, population: &Population<FDim, GDim>, _iteration: usize) -> bool {
+        (self.extract)(population) <= self.target
+    }
+}
+
+/// Stops the search once a wall-clock time budget has elapsed, measured
+/// from the moment the criterion is constructed.
+#[derive(Debug)]
+pub struct TimeBudgetStoppingCriterion<FDim, GDim> {
+    start: Instant,
+    budget: Duration,
+    _phantom: std::marker::PhantomData<(FDim, GDim)>,
+}
+
+impl<FDim, GDim> TimeBudgetStoppingCriterion<FDim, GDim> {
+    pub fn new(budget: Duration) -> Self {
+        Self {
+            start: Instant::now(),
+            budget,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<FDim, GDim> StoppingCriterion<FDim, GDim> for TimeBudgetStoppingCriterion<FDim, GDim>
+where
+    FDim: D12,
+    GDim: D12,
+{
+    fn should_stop(&mut self, _population: &Population<FDim, GDim>, _iteration: usize) -> bool {
+        self.start.elapsed() >= self.budget
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::genetic::Population;
+    use ndarray::{Array1, array};
+
+    fn population_with_best(best: f64) -> Population<ndarray::Ix1, ndarray::Ix1> {
+        let genes = array![[0.0], [1.0]];
+        let fitness: Array1<f64> = array![best, best + 1.0];
+        let constraints: Array1<f64> = array![0.0, 0.0];
+        Population::new(genes, fitness, constraints)
+    }
+
+    fn extract_best(population: &Population<ndarray::Ix1, ndarray::Ix1>) -> f64 {
+        population.fitness.iter().cloned().fold(f64::INFINITY, f64::min)
+    }
+
+    #[test]
+    fn stagnation_triggers_after_limit_consecutive_non_improvements() {
+        let mut criterion = StagnationStoppingCriterion::new(extract_best, 1e-9, 2);
+
+        assert!(!criterion.should_stop(&population_with_best(1.0), 1));
+        // Same best value as before: stagnation_count becomes 1, still below limit.
+        assert!(!criterion.should_stop(&population_with_best(1.0), 2));
+        // Second consecutive non-improvement reaches the limit.
+        assert!(criterion.should_stop(&population_with_best(1.0), 3));
+    }
+
+    #[test]
+    fn stagnation_resets_on_improvement() {
+        let mut criterion = StagnationStoppingCriterion::new(extract_best, 1e-9, 1);
+
+        assert!(!criterion.should_stop(&population_with_best(1.0), 1));
+        assert!(criterion.should_stop(&population_with_best(1.0), 2));
+        // An improved value resets the stagnation counter.
+        assert!(!criterion.should_stop(&population_with_best(0.5), 3));
+    }
+
+    /// At large fitness magnitudes, `epsilon` must compare as a *relative*
+    /// tolerance, not an absolute one: a 5.0 absolute difference against a
+    /// ~1e6 magnitude is only a ~5e-6 relative change, well inside a 1e-3
+    /// relative tolerance, so it counts as stagnation even though the
+    /// absolute difference is large in isolation.
+    #[test]
+    fn stagnation_uses_relative_not_absolute_tolerance() {
+        let mut criterion = StagnationStoppingCriterion::new(extract_best, 1e-3, 1);
+
+        assert!(!criterion.should_stop(&population_with_best(1_000_000.0), 1));
+        assert!(criterion.should_stop(&population_with_best(999_995.0), 2));
+    }
+
+    #[test]
+    fn target_fitness_stops_once_target_is_reached() {
+        let mut criterion = TargetFitnessStoppingCriterion::new(extract_best, 0.5);
+
+        assert!(!criterion.should_stop(&population_with_best(1.0), 1));
+        assert!(criterion.should_stop(&population_with_best(0.4), 2));
+    }
+
+    #[test]
+    fn time_budget_stops_only_after_the_budget_elapses() {
+        let mut criterion: TimeBudgetStoppingCriterion<ndarray::Ix1, ndarray::Ix1> =
+            TimeBudgetStoppingCriterion::new(Duration::from_millis(20));
+
+        assert!(!criterion.should_stop(&population_with_best(1.0), 1));
+        std::thread::sleep(Duration::from_millis(30));
+        assert!(criterion.should_stop(&population_with_best(1.0), 2));
+    }
+}
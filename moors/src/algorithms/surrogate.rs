@@ -0,0 +1,188 @@
+use ndarray::{Array, Array2, Dimension, IxDyn, RemoveAxis};
+
+use crate::{genetic::D12, helpers::linalg::cross_euclidean_distances};
+
+/// A cheap model standing in for an expensive `FitnessFn`, used to screen
+/// offspring before they reach the real evaluator. `GeneticAlgorithm`
+/// refits it each generation on its current (truly-evaluated) population;
+/// evaluated genomes that did not survive into that population are not
+/// part of the training set.
+pub trait Surrogate<FDim>
+where
+    FDim: D12,
+{
+    /// Fits the model on genomes that have already been through the real
+    /// evaluator, alongside their true fitness.
+    fn fit(&mut self, genes: &Array2<f64>, fitness: &Array<f64, FDim>);
+
+    /// Predicts the fitness of `candidates` without calling the real
+    /// evaluator.
+    fn predict(&self, candidates: &Array2<f64>) -> Array<f64, FDim>;
+}
+
+fn flatten_cols<D: Dimension>(arr: &Array<f64, D>) -> usize {
+    let shape = arr.shape();
+    if shape.len() == 1 { 1 } else { shape[1] }
+}
+
+fn stack_like<D: Dimension>(template: &Array<f64, D>, rows: &[Vec<f64>]) -> Array<f64, D> {
+    let ncols = flatten_cols(template);
+    let nrows = rows.len();
+    let flat: Vec<f64> = rows.iter().flatten().cloned().collect();
+    let shape: Vec<usize> = if template.shape().len() == 1 {
+        vec![nrows]
+    } else {
+        vec![nrows, ncols]
+    };
+    Array::from_shape_vec(IxDyn(&shape), flat)
+        .expect("surrogate prediction shape mismatch")
+        .into_dimensionality::<D>()
+        .expect("surrogate prediction has the wrong number of dimensions")
+}
+
+/// A k-nearest-neighbor surrogate: predicts a candidate's fitness as the
+/// average of the `k` fitted genomes closest to it in decision-variable
+/// space, measured via [`cross_euclidean_distances`].
+pub struct KnnSurrogate<FDim>
+where
+    FDim: D12,
+{
+    k: usize,
+    genes: Option<Array2<f64>>,
+    fitness: Option<Array<f64, FDim>>,
+}
+
+impl<FDim> KnnSurrogate<FDim>
+where
+    FDim: D12,
+{
+    pub fn new(k: usize) -> Self {
+        Self {
+            k,
+            genes: None,
+            fitness: None,
+        }
+    }
+}
+
+impl<FDim> Surrogate<FDim> for KnnSurrogate<FDim>
+where
+    FDim: D12 + RemoveAxis,
+{
+    fn fit(&mut self, genes: &Array2<f64>, fitness: &Array<f64, FDim>) {
+        self.genes = Some(genes.clone());
+        self.fitness = Some(fitness.clone());
+    }
+
+    fn predict(&self, candidates: &Array2<f64>) -> Array<f64, FDim> {
+        let genes = self
+            .genes
+            .as_ref()
+            .expect("KnnSurrogate::predict called before fit");
+        let fitness = self
+            .fitness
+            .as_ref()
+            .expect("KnnSurrogate::predict called before fit");
+
+        let distances = cross_euclidean_distances(candidates, genes);
+        let ncols = flatten_cols(fitness);
+        let flat_fitness: Vec<f64> = fitness.iter().cloned().collect();
+        let k = self.k.min(genes.nrows()).max(1);
+
+        let rows: Vec<Vec<f64>> = (0..candidates.nrows())
+            .map(|i| {
+                let mut neighbors: Vec<usize> = (0..genes.nrows()).collect();
+                neighbors.sort_by(|&a, &b| {
+                    distances[[i, a]]
+                        .partial_cmp(&distances[[i, b]])
+                        .unwrap()
+                });
+                neighbors.truncate(k);
+                let mut sums = vec![0.0; ncols];
+                for &n in &neighbors {
+                    for c in 0..ncols {
+                        sums[c] += flat_fitness[n * ncols + c];
+                    }
+                }
+                sums.iter().map(|s| s / neighbors.len() as f64).collect()
+            })
+            .collect();
+
+        stack_like(fitness, &rows)
+    }
+}
+
+/// Ranks `predicted` rows by predicted dominance (fewest other rows
+/// dominating it first) and returns the indices of the `q` most
+/// promising candidates.
+pub(crate) fn select_top_q<D: Dimension>(predicted: &Array<f64, D>, q: usize) -> Vec<usize> {
+    let ncols = flatten_cols(predicted);
+    let flat: Vec<f64> = predicted.iter().cloned().collect();
+    let nrows = flat.len() / ncols.max(1);
+    let row = |i: usize| &flat[i * ncols..(i + 1) * ncols];
+
+    let mut indices: Vec<usize> = (0..nrows).collect();
+    indices.sort_by_key(|&i| {
+        (0..nrows)
+            .filter(|&j| {
+                j != i
+                    && row(j).iter().zip(row(i)).all(|(a, b)| a <= b)
+                    && row(j).iter().zip(row(i)).any(|(a, b)| a < b)
+            })
+            .count()
+    });
+    indices.truncate(q.min(nrows));
+    indices
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::{Ix1, array};
+
+    #[test]
+    fn predict_averages_the_k_nearest_fitted_neighbors() {
+        let genes = array![[0.0, 0.0], [10.0, 10.0], [1.0, 0.0]];
+        let fitness: Array<f64, Ix1> = array![1.0, 100.0, 2.0];
+
+        let mut surrogate: KnnSurrogate<Ix1> = KnnSurrogate::new(2);
+        surrogate.fit(&genes, &fitness);
+
+        // Candidate [0.0, 0.0] is closest to genes[0] (distance 0) and
+        // genes[2] (distance 1.0); genes[1] (distance ~14.14) is excluded
+        // from the k=2 neighborhood, so the prediction averages only 1.0
+        // and 2.0.
+        let predicted = surrogate.predict(&array![[0.0, 0.0]]);
+        assert!((predicted[0] - 1.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn predict_clamps_k_to_the_number_of_fitted_points() {
+        let genes = array![[0.0, 0.0], [10.0, 10.0], [1.0, 0.0]];
+        let fitness: Array<f64, Ix1> = array![1.0, 100.0, 2.0];
+
+        let mut surrogate: KnnSurrogate<Ix1> = KnnSurrogate::new(5);
+        surrogate.fit(&genes, &fitness);
+
+        // k=5 exceeds the 3 fitted points, so it is clamped to 3 and every
+        // fitted point contributes to the average.
+        let predicted = surrogate.predict(&array![[0.0, 0.0]]);
+        assert!((predicted[0] - (1.0 + 100.0 + 2.0) / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn select_top_q_ranks_by_dominance_count_ascending() {
+        let predicted = array![[1.0, 4.0], [2.0, 2.0], [3.0, 3.0], [0.0, 5.0]];
+
+        // Row 2 ([3.0, 3.0]) is the only one dominated by another row
+        // ([2.0, 2.0]); the rest are mutually non-dominating, so a q=3
+        // selection keeps every row except the dominated one.
+        assert_eq!(select_top_q(&predicted, 3), vec![0, 1, 3]);
+    }
+
+    #[test]
+    fn select_top_q_truncates_to_the_requested_count() {
+        let predicted = array![[1.0, 4.0], [2.0, 2.0], [3.0, 3.0], [0.0, 5.0]];
+        assert_eq!(select_top_q(&predicted, 2).len(), 2);
+    }
+}
@@ -0,0 +1,141 @@
+//! Dominated hypervolume indicator for MOO convergence tracking.
+//!
+//! The hypervolume of a front is the volume of objective space dominated
+//! by its non-dominated solutions, bounded above by a reference point.
+//! Larger is better. It is reported as a per-generation metric in
+//! [`GenerationStats::hypervolume`](crate::algorithms::GenerationStats) once a
+//! reference point is set with `GeneticAlgorithm::set_hypervolume_reference`;
+//! [`IterationData`](crate::algorithms::IterationData) does not carry it. It can also drive
+//! [`StagnationStoppingCriterion`](crate::algorithms::StagnationStoppingCriterion)
+//! by using [`Hypervolume::compute`] as the extractor closure.
+
+use ndarray::{Array1, Array2, Axis};
+
+/// Computes the dominated hypervolume of a population's non-dominated
+/// front relative to a reference point. Objectives are assumed to be
+/// minimized; points that do not dominate `reference` are discarded.
+pub struct Hypervolume;
+
+impl Hypervolume {
+    pub fn compute(front: &Array2<f64>, reference: &Array1<f64>) -> f64 {
+        let dominating: Vec<Vec<f64>> = non_dominated_rows(front)
+            .into_iter()
+            .filter(|row| row.iter().zip(reference.iter()).all(|(x, r)| x < r))
+            .collect();
+
+        if dominating.is_empty() {
+            return 0.0;
+        }
+
+        if reference.len() == 2 {
+            hv_2d(&dominating, reference)
+        } else {
+            let dims: Vec<usize> = (0..reference.len()).collect();
+            hso(&dominating, reference, &dims)
+        }
+    }
+}
+
+/// Filters `front`'s rows down to the non-dominated set (minimization).
+fn non_dominated_rows(front: &Array2<f64>) -> Vec<Vec<f64>> {
+    let rows: Vec<Vec<f64>> = front.axis_iter(Axis(0)).map(|row| row.to_vec()).collect();
+    rows.iter()
+        .enumerate()
+        .filter(|(i, candidate)| {
+            !rows.iter().enumerate().any(|(j, other)| {
+                j != *i
+                    && other.iter().zip(candidate.iter()).all(|(o, c)| o <= c)
+                    && other.iter().zip(candidate.iter()).any(|(o, c)| o < c)
+            })
+        })
+        .map(|(_, row)| row.clone())
+        .collect()
+}
+
+/// Closed-form sweep for two objectives: sort ascending by objective 0 and
+/// accumulate rectangle slices against the running best objective 1.
+fn hv_2d(points: &[Vec<f64>], reference: &Array1<f64>) -> f64 {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a[0].partial_cmp(&b[0]).unwrap());
+
+    let mut sum = 0.0;
+    let mut prev_best_y = reference[1];
+    for p in &sorted {
+        let width = reference[0] - p[0];
+        let height = prev_best_y - p[1];
+        if width > 0.0 && height > 0.0 {
+            sum += width * height;
+        }
+        prev_best_y = prev_best_y.min(p[1]);
+    }
+    sum
+}
+
+/// Hypervolume-by-Slicing-Objectives (HSO) recursion for more than two
+/// objectives: slice along `dims[0]`, and for each slice recurse over the
+/// remaining objectives using only the points that dominate that slice.
+fn hso(points: &[Vec<f64>], reference: &Array1<f64>, dims: &[usize]) -> f64 {
+    if points.is_empty() {
+        return 0.0;
+    }
+    let d = dims[0];
+    if dims.len() == 1 {
+        let best = points
+            .iter()
+            .map(|p| p[d])
+            .fold(f64::INFINITY, f64::min);
+        return (reference[d] - best).max(0.0);
+    }
+
+    let rest = &dims[1..];
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a[d].partial_cmp(&b[d]).unwrap());
+
+    let mut volume = 0.0;
+    for i in 0..sorted.len() {
+        let upper = if i + 1 < sorted.len() {
+            sorted[i + 1][d]
+        } else {
+            reference[d]
+        };
+        let thickness = upper - sorted[i][d];
+        if thickness > 0.0 {
+            // Points with a `d`-coordinate <= `sorted[i][d]` are the ones
+            // that dominate this slice (lower is better under
+            // minimization), i.e. the prefix `sorted[..=i]`, not the
+            // suffix: the suffix holds points *worse* on `d`, which don't
+            // bound the slice at all and wildly overstate the volume.
+            volume += thickness * hso(&sorted[..=i], reference, rest);
+        }
+    }
+    volume
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+
+    /// Manual sweep over the sorted front: rectangle slices against the
+    /// reference point are (5-1)*(5-4) + (5-2)*(4-2) + (5-4)*(2-1) = 4+6+1 = 11.
+    #[test]
+    fn hv_2d_matches_manual_rectangle_sum() {
+        let front = array![[1.0, 4.0], [2.0, 2.0], [4.0, 1.0]];
+        let reference = array![5.0, 5.0];
+
+        assert_eq!(Hypervolume::compute(&front, &reference), 11.0);
+    }
+
+    /// Hand-verified against the true union-of-boxes volume for 2 points in
+    /// 3 objectives: box([1,3,3]..[4,4,4]) has volume 3*1*1=3, box([3,1,3]..
+    /// [4,4,4]) has volume 1*3*1=3, their intersection ([3,4]x[3,4]x[3,4])
+    /// has volume 1, so the union is 3+3-1=5. The suffix-slice regression
+    /// this guards against returned a wildly inflated volume here.
+    #[test]
+    fn hso_matches_manual_union_of_boxes_volume() {
+        let front = array![[1.0, 3.0, 3.0], [3.0, 1.0, 3.0]];
+        let reference = array![4.0, 4.0, 4.0];
+
+        assert_eq!(Hypervolume::compute(&front, &reference), 5.0);
+    }
+}
@@ -84,6 +84,7 @@
 //! * [`evaluator`](crate::evaluator)  – fitness + constraints evaluation pipeline
 //! * [`random`](crate::random)        – pluggable RNG abstraction
 //! * [`duplicates`](crate::duplicates) – duplicate‑handling strategies
+//! * [`hypervolume`](crate::hypervolume) – dominated hypervolume indicator
 //!
 //! ---
 
@@ -95,14 +96,19 @@ pub mod duplicates;
 pub mod evaluator;
 pub mod genetic;
 pub(crate) mod helpers;
+pub mod hypervolume;
 pub mod non_dominated_sorting;
 pub mod operators;
 mod private;
 pub mod random;
 pub use algorithms::{
-    AgeMoea, AgeMoeaBuilder, AlgorithmBuilder, AlgorithmBuilderError, AlgorithmError,
-    GeneticAlgorithm, Ibea, IbeaBuilder, InitializationError, IterationData, Nsga2, Nsga2Builder,
-    Nsga3, Nsga3Builder, Revea, ReveaBuilder, Rnsga2, Rnsga2Builder, Spea2, Spea2Builder,
+    AgeMoea, AgeMoeaBuilder, AlgorithmBuilder, AlgorithmBuilderError, AlgorithmError, Checkpoint,
+    ConstantRate, CsvStatsSink, EvaluationCache, GeneticAlgorithm, GenerationStats, Ibea,
+    IbeaBuilder, InitializationError, IterationData, JsonStatsSink, KnnSurrogate, LinearRate,
+    MutationRate, Nsga2, Nsga2Builder, Nsga3, Nsga3Builder, ObjectiveStats, Revea, ReveaBuilder,
+    Rnsga2, Rnsga2Builder, RunResult, SelectionRate, SlopeAdaptiveRate, Spea2, Spea2Builder,
+    StagnationStoppingCriterion, StatsSink, StoppingCriterion, Surrogate,
+    TargetFitnessStoppingCriterion, TimeBudgetStoppingCriterion,
 };
 pub use duplicates::{
     CloseDuplicatesCleaner, ExactDuplicatesCleaner, NoDuplicatesCleaner, PopulationCleaner,
@@ -112,6 +118,7 @@ pub use genetic::{
     Individual, IndividualMOO, IndividualSOO, Population, PopulationMOO, PopulationSOO,
 };
 pub use helpers::linalg::cross_euclidean_distances;
+pub use hypervolume::Hypervolume;
 pub use operators::selection;
 pub use operators::survival;
 pub use operators::{
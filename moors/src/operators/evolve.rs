@@ -0,0 +1,155 @@
+use std::fmt;
+
+use ndarray::{Array2, Axis, concatenate};
+
+use crate::{
+    duplicates::PopulationCleaner,
+    genetic::Population,
+    operators::{CrossoverOperator, MutationOperator, SelectionOperator},
+    random::MOORandomGenerator,
+};
+
+/// Orchestrates one generation's offspring production: selection, crossover
+/// and mutation, retried up to a caller-supplied attempt budget until enough
+/// candidates have survived the duplicates cleaner.
+pub struct Evolve<Sel, Cross, Mut, DC> {
+    selection: Sel,
+    crossover: Cross,
+    mutation: Mut,
+    pub(crate) duplicates_cleaner: DC,
+    crossover_rate: f64,
+    mutation_rate: f64,
+    selection_rate: f64,
+}
+
+impl<Sel, Cross, Mut, DC> Evolve<Sel, Cross, Mut, DC> {
+    pub fn new(
+        selection: Sel,
+        crossover: Cross,
+        mutation: Mut,
+        duplicates_cleaner: DC,
+        crossover_rate: f64,
+        mutation_rate: f64,
+    ) -> Self {
+        Self {
+            selection,
+            crossover,
+            mutation,
+            duplicates_cleaner,
+            crossover_rate,
+            mutation_rate,
+            // Selection pressure has no build-time knob of its own (unlike
+            // crossover/mutation rate): it only exists once a `SelectionRate`
+            // is installed on the algorithm, so it starts at full pressure.
+            selection_rate: 1.0,
+        }
+    }
+
+    /// Overrides the crossover/mutation-step mutation rate, replacing the
+    /// value fixed at construction. Called once per generation by
+    /// [`GeneticAlgorithm::next`](crate::algorithms::GeneticAlgorithm) when
+    /// a [`MutationRate`](crate::algorithms::MutationRate) is installed.
+    pub fn set_mutation_rate(&mut self, rate: f64) {
+        self.mutation_rate = rate;
+    }
+
+    /// Overrides the selection pressure applied ahead of crossover,
+    /// replacing the full-pressure default of `1.0` set at construction.
+    /// Called once per generation by
+    /// [`GeneticAlgorithm::next`](crate::algorithms::GeneticAlgorithm) when
+    /// a [`SelectionRate`](crate::algorithms::SelectionRate) is installed.
+    pub fn set_selection_rate(&mut self, rate: f64) {
+        self.selection_rate = rate;
+    }
+}
+
+impl<Sel, Cross, Mut, DC> fmt::Debug for Evolve<Sel, Cross, Mut, DC> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Evolve")
+            .field("crossover_rate", &self.crossover_rate)
+            .field("mutation_rate", &self.mutation_rate)
+            .field("selection_rate", &self.selection_rate)
+            .finish()
+    }
+}
+
+/// Errors produced while evolving one generation's offspring.
+#[derive(Debug)]
+pub enum EvolveError {
+    /// Mating produced no offspring surviving duplicate removal within the
+    /// attempt budget.
+    EmptyMatingResult,
+}
+
+impl fmt::Display for EvolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyMatingResult => write!(
+                f,
+                "mating produced no offspring surviving duplicate removal within the attempt budget"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for EvolveError {}
+
+impl<Sel, Cross, Mut, DC> Evolve<Sel, Cross, Mut, DC>
+where
+    Sel: SelectionOperator,
+    Cross: CrossoverOperator,
+    Mut: MutationOperator,
+    DC: PopulationCleaner,
+{
+    /// Produces `num_offsprings` genomes from `population` by repeatedly
+    /// selecting parents, crossing them over and mutating the result,
+    /// cleaning duplicates out of the combined attempts until the target
+    /// count is reached or `max_attempts` rounds have run.
+    pub fn evolve<FDim, GDim>(
+        &mut self,
+        population: &Population<FDim, GDim>,
+        num_offsprings: usize,
+        max_attempts: usize,
+        rng: &mut MOORandomGenerator,
+    ) -> Result<Array2<f64>, EvolveError>
+    where
+        Sel: SelectionOperator<FDim = FDim>,
+        FDim: crate::genetic::D12,
+        GDim: crate::genetic::D12,
+    {
+        let mut collected: Option<Array2<f64>> = None;
+
+        for _ in 0..max_attempts {
+            let rows_so_far = collected.as_ref().map_or(0, |c| c.nrows());
+            if rows_so_far >= num_offsprings {
+                break;
+            }
+
+            let parents = self.selection.operate(population, self.selection_rate, rng);
+            let offspring = self.crossover.operate(&parents, self.crossover_rate, rng);
+            let mutated = self.mutation.operate(&offspring, self.mutation_rate, rng);
+            let cleaned = self.duplicates_cleaner.operate(mutated);
+
+            if cleaned.nrows() == 0 {
+                continue;
+            }
+
+            collected = Some(match collected {
+                Some(existing) => {
+                    let combined = concatenate(Axis(0), &[existing.view(), cleaned.view()])
+                        .expect("failed to concatenate offspring batches");
+                    self.duplicates_cleaner.operate(combined)
+                }
+                None => cleaned,
+            });
+        }
+
+        match collected {
+            Some(genes) if genes.nrows() > 0 => {
+                let take = genes.nrows().min(num_offsprings);
+                Ok(genes.select(Axis(0), &(0..take).collect::<Vec<_>>()))
+            }
+            _ => Err(EvolveError::EmptyMatingResult),
+        }
+    }
+}